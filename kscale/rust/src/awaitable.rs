@@ -0,0 +1,43 @@
+//! A stub-aware wrapper around the coroutine objects our async bindings return.
+
+use std::marker::PhantomData;
+
+use pyo3::prelude::*;
+use pyo3_stub_gen::{PyStubType, TypeInfo};
+
+/// Wraps the `Bound<PyAny>` coroutine object `pyo3_asyncio::tokio::future_into_py`
+/// hands back, purely so `#[gen_stub_pyfunction]` knows what to write to the `.pyi`.
+///
+/// `future_into_py`'s return type carries no information about what the coroutine
+/// resolves to, so a `#[pyfunction]` returning it bare stubs as `-> typing.Any`. This
+/// newtype is a zero-cost pass-through at runtime (`into_py` just forwards the inner
+/// value) but lets us implement `PyStubType` to emit `typing.Awaitable[T]` instead.
+pub struct PyAwaitable<'py, T> {
+    inner: Bound<'py, PyAny>,
+    _resolves_to: PhantomData<T>,
+}
+
+impl<'py, T> PyAwaitable<'py, T> {
+    pub fn new(inner: Bound<'py, PyAny>) -> Self {
+        Self {
+            inner,
+            _resolves_to: PhantomData,
+        }
+    }
+}
+
+impl<'py, T> IntoPy<Py<PyAny>> for PyAwaitable<'py, T> {
+    fn into_py(self, py: Python<'_>) -> Py<PyAny> {
+        self.inner.into_py(py)
+    }
+}
+
+impl<'py, T: PyStubType> PyStubType for PyAwaitable<'py, T> {
+    fn type_output() -> TypeInfo {
+        let inner = T::type_output();
+        TypeInfo {
+            name: format!("typing.Awaitable[{}]", inner.name),
+            import: inner.import,
+        }
+    }
+}
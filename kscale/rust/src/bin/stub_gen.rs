@@ -1,7 +1,17 @@
+use std::fs::OpenOptions;
+use std::io::Write as _;
+
 use pyo3_stub_gen::Result;
 
 fn main() -> Result<()> {
     let stub = kscale::stub_info()?;
     stub.generate()?;
+
+    // The exception classes in `kscale::error` are defined with `create_exception!`,
+    // which the stub gatherer above can't see (see `kscale::error::EXCEPTION_STUBS`
+    // for why), so append their declarations to the generated `.pyi` by hand.
+    let mut pyi = OpenOptions::new().append(true).open("kscale/rust.pyi")?;
+    pyi.write_all(kscale::error::EXCEPTION_STUBS.as_bytes())?;
+
     Ok(())
 }
@@ -0,0 +1,82 @@
+//! Crate-level error type and the Python exception classes it maps to.
+
+use pyo3::create_exception;
+use pyo3::exceptions::PyException;
+use pyo3::prelude::*;
+
+// The first argument becomes the `__module__` these report, so it must match where
+// `register` below actually adds them: as attributes of the `rust` submodule, which
+// Python code reaches as `kscale.rust` (see `kscale/rust.pyi` in the stub output) —
+// not the top-level `kscale` package, which nothing here defines.
+create_exception!(rust, AuthError, PyException, "Authentication with the K-Scale API failed.");
+create_exception!(rust, ArtifactNotFound, PyException, "The requested artifact does not exist.");
+create_exception!(rust, ChecksumError, PyException, "A downloaded or uploaded artifact failed its checksum check.");
+create_exception!(rust, NetworkError, PyException, "A network request to the K-Scale API failed.");
+create_exception!(rust, IoError, PyException, "A local filesystem operation (e.g. writing a download) failed.");
+
+/// Errors that can occur while talking to the K-Scale API or the local filesystem.
+#[derive(Debug)]
+pub enum KScaleError {
+    Network(String),
+    Auth(String),
+    NotFound(String),
+    Checksum(String),
+    Io(std::io::Error),
+}
+
+impl std::fmt::Display for KScaleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Network(msg) => write!(f, "network error: {msg}"),
+            Self::Auth(msg) => write!(f, "auth error: {msg}"),
+            Self::NotFound(msg) => write!(f, "not found: {msg}"),
+            Self::Checksum(msg) => write!(f, "checksum mismatch: {msg}"),
+            Self::Io(err) => write!(f, "io error: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for KScaleError {}
+
+impl From<std::io::Error> for KScaleError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl From<KScaleError> for PyErr {
+    fn from(err: KScaleError) -> Self {
+        match err {
+            KScaleError::Network(_) => NetworkError::new_err(err.to_string()),
+            KScaleError::Auth(_) => AuthError::new_err(err.to_string()),
+            KScaleError::NotFound(_) => ArtifactNotFound::new_err(err.to_string()),
+            KScaleError::Checksum(_) => ChecksumError::new_err(err.to_string()),
+            KScaleError::Io(_) => IoError::new_err(err.to_string()),
+        }
+    }
+}
+
+/// Registers every exception class defined in this module on the `rust` pymodule.
+pub fn register(m: &Bound<PyModule>) -> PyResult<()> {
+    m.add("AuthError", m.py().get_type::<AuthError>())?;
+    m.add("ArtifactNotFound", m.py().get_type::<ArtifactNotFound>())?;
+    m.add("ChecksumError", m.py().get_type::<ChecksumError>())?;
+    m.add("NetworkError", m.py().get_type::<NetworkError>())?;
+    m.add("IoError", m.py().get_type::<IoError>())?;
+    Ok(())
+}
+
+/// Hand-written `.pyi` stanzas for the exception classes above.
+///
+/// `create_exception!` defines a plain Python type at runtime; unlike `#[pyclass]`
+/// types tagged `#[gen_stub_pyclass]`, it has no compile-time hook the stub gatherer
+/// can see, so `kscale::stub_info()` never emits these. Until pyo3-stub-gen grows
+/// native support for `create_exception!`, `bin/stub_gen.rs` appends this text to the
+/// generated `.pyi` by hand so `except kscale.rust.AuthError` etc. still type-check.
+pub const EXCEPTION_STUBS: &str = "\
+class AuthError(Exception): ...
+class ArtifactNotFound(Exception): ...
+class ChecksumError(Exception): ...
+class NetworkError(Exception): ...
+class IoError(Exception): ...
+";
@@ -0,0 +1,64 @@
+//! Delivers Ctrl-C to long-running transfers without seizing the process's signal
+//! handling away from CPython.
+//!
+//! `Python::check_signals` only runs the pending Python-level signal handler when
+//! called from the interpreter's main thread; everywhere else it is a no-op. The
+//! futures behind `download_artifact`/`upload_artifact` are polled on a tokio worker
+//! thread (see `runtime.rs`), so calling `check_signals` directly from inside them
+//! never observes a `KeyboardInterrupt`. Rather than install our own OS signal handler
+//! (which would shadow CPython's), we hop back to the thread actually running the
+//! asyncio event loop — typically the interpreter's main thread — via
+//! `loop.call_soon_threadsafe`, call `check_signals` there, and report the result back
+//! over a channel.
+
+use pyo3::prelude::*;
+use pyo3::types::{PyCFunction, PyDict, PyTuple};
+
+/// A handle to the asyncio event loop driving the coroutine that awaits a transfer,
+/// captured on the interpreter thread before the transfer's future is handed off to
+/// the tokio runtime. Cheap to clone — `check` is called once per chunk, and cloning
+/// before each call keeps every call's future self-contained instead of borrowing
+/// from a shared closure environment.
+#[derive(Clone)]
+pub struct InterruptChecker {
+    event_loop: Py<PyAny>,
+}
+
+impl InterruptChecker {
+    /// Captures the event loop currently running on the calling (interpreter) thread.
+    pub fn capture(py: Python<'_>) -> PyResult<Self> {
+        Ok(Self {
+            event_loop: pyo3_asyncio::tokio::get_current_loop(py)?.into(),
+        })
+    }
+
+    /// Runs `Python::check_signals` on the event loop's thread and raises whatever it
+    /// raises. Safe to call from any thread, including a tokio worker thread.
+    pub async fn check(&self) -> PyResult<()> {
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        let tx = std::sync::Mutex::new(Some(tx));
+
+        Python::with_gil(|py| -> PyResult<()> {
+            let callback = PyCFunction::new_closure(
+                py,
+                None,
+                None,
+                move |_args: &Bound<'_, PyTuple>, _kwargs: Option<&Bound<'_, PyDict>>| {
+                    let result = Python::with_gil(|py| py.check_signals());
+                    if let Some(tx) = tx.lock().unwrap().take() {
+                        let _ = tx.send(result);
+                    }
+                },
+            )?;
+            self.event_loop
+                .call_method1(py, "call_soon_threadsafe", (callback,))?;
+            Ok(())
+        })?;
+
+        match rx.await {
+            Ok(result) => result,
+            // The event loop shut down before running the callback; nothing pending.
+            Err(_) => Ok(()),
+        }
+    }
+}
@@ -1,16 +1,135 @@
+use std::path::PathBuf;
+
 use pyo3::prelude::*;
 use pyo3_stub_gen::define_stub_info_gatherer;
 use pyo3_stub_gen::derive::gen_stub_pyfunction;
 
+mod awaitable;
+pub mod error;
+mod interrupt;
+mod models;
+mod net;
+mod runtime;
+
+use awaitable::PyAwaitable;
+use interrupt::InterruptChecker;
+use models::Artifact;
+
 #[pyfunction]
 #[gen_stub_pyfunction]
 pub fn hello_world() {
     println!("Hello, world!");
 }
 
+/// Downloads `artifact_id` to `dest`, returning the destination path once complete.
+///
+/// Checks for Ctrl-C between chunks; if one lands, the partial file at `dest` is
+/// removed and a `KeyboardInterrupt` propagates to the awaiting Python code. Every
+/// chunk here is plain async I/O polled on the tokio runtime, never a blocking call
+/// made while holding the GIL, so unlike a synchronous chunked transfer there is no
+/// section to wrap in `py.allow_threads` — the GIL is only acquired briefly, in
+/// `Python::with_gil`: to schedule each chunk's signal check, to classify an error,
+/// and to build the value handed back to Python.
+#[pyfunction]
+#[gen_stub_pyfunction]
+pub fn download_artifact(
+    py: Python<'_>,
+    artifact_id: String,
+    dest: PathBuf,
+) -> PyResult<PyAwaitable<'_, PathBuf>> {
+    let checker = InterruptChecker::capture(py)?;
+    let awaitable = pyo3_asyncio::tokio::future_into_py(py, async move {
+        let result = net::download_artifact(artifact_id, dest.clone(), || {
+            let checker = checker.clone();
+            async move { checker.check().await }
+        })
+        .await;
+        match result {
+            Ok(path) => Python::with_gil(|py| Ok(path.into_py(py))),
+            Err(err) => {
+                // Only a dangling partial download warrants cleanup here — a domain
+                // error like `ArtifactNotFound` never wrote anything to `dest`.
+                if is_keyboard_interrupt(&err) {
+                    let _ = std::fs::remove_file(&dest);
+                }
+                Err(err)
+            }
+        }
+    })?;
+    Ok(PyAwaitable::new(awaitable))
+}
+
+/// Uploads the file at `path`, returning the id of the newly created artifact.
+///
+/// Checks for Ctrl-C between chunks, same contract as [`download_artifact`].
+#[pyfunction]
+#[gen_stub_pyfunction]
+pub fn upload_artifact(py: Python<'_>, path: PathBuf) -> PyResult<PyAwaitable<'_, String>> {
+    let checker = InterruptChecker::capture(py)?;
+    let awaitable = pyo3_asyncio::tokio::future_into_py(py, async move {
+        let artifact_id = net::upload_artifact(path, || {
+            let checker = checker.clone();
+            async move { checker.check().await }
+        })
+        .await?;
+        Python::with_gil(|py| Ok(artifact_id.into_py(py)))
+    })?;
+    Ok(PyAwaitable::new(awaitable))
+}
+
+/// Whether `err` is a `KeyboardInterrupt` (or a subclass of it), as raised by
+/// [`interrupt::InterruptChecker::check`].
+fn is_keyboard_interrupt(err: &PyErr) -> bool {
+    Python::with_gil(|py| err.is_instance_of::<pyo3::exceptions::PyKeyboardInterrupt>(py))
+}
+
+/// Lists the artifacts visible to the current auth token.
+#[pyfunction]
+#[gen_stub_pyfunction]
+pub fn list_artifacts(py: Python<'_>) -> PyResult<PyAwaitable<'_, Vec<Artifact>>> {
+    let awaitable = pyo3_asyncio::tokio::future_into_py(py, async move {
+        let artifacts: Vec<Artifact> = net::list_artifacts()
+            .await?
+            .into_iter()
+            .map(|raw| Artifact {
+                id: raw.id,
+                name: raw.name,
+                size: raw.size,
+                urdf_path: raw.urdf_path,
+                mjcf_path: raw.mjcf_path,
+            })
+            .collect();
+        Python::with_gil(|py| Ok(artifacts.into_py(py)))
+    })?;
+    Ok(PyAwaitable::new(awaitable))
+}
+
+/// Exchanges `refresh_token` for a fresh access token.
+#[pyfunction]
+#[gen_stub_pyfunction]
+pub fn refresh_auth_token(py: Python<'_>, refresh_token: String) -> PyResult<PyAwaitable<'_, String>> {
+    let awaitable = pyo3_asyncio::tokio::future_into_py(py, async move {
+        let access_token = net::refresh_auth_token(refresh_token).await?;
+        Python::with_gil(|py| Ok(access_token.into_py(py)))
+    })?;
+    Ok(PyAwaitable::new(awaitable))
+}
+
 #[pymodule]
 fn rust(m: &Bound<PyModule>) -> PyResult<()> {
+    runtime::init();
+
     m.add_function(wrap_pyfunction!(hello_world, m)?)?;
+    m.add_function(wrap_pyfunction!(download_artifact, m)?)?;
+    m.add_function(wrap_pyfunction!(upload_artifact, m)?)?;
+    m.add_function(wrap_pyfunction!(list_artifacts, m)?)?;
+    m.add_function(wrap_pyfunction!(refresh_auth_token, m)?)?;
+
+    m.add_class::<models::Artifact>()?;
+    m.add_class::<models::RobotMetadata>()?;
+
+    error::register(m)?;
+
     Ok(())
 }
 
@@ -0,0 +1,95 @@
+//! Typed return values for the `rust` module's listing and metadata calls.
+
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use pyo3_stub_gen::derive::{gen_stub_pyclass, gen_stub_pymethods};
+
+/// A single downloadable artifact (model weights, urdf/mjcf bundle, etc).
+#[gen_stub_pyclass]
+#[pyclass(get_all)]
+#[derive(Debug, Clone)]
+pub struct Artifact {
+    pub id: String,
+    pub name: String,
+    pub size: u64,
+    pub urdf_path: Option<String>,
+    pub mjcf_path: Option<String>,
+}
+
+#[gen_stub_pymethods]
+#[pymethods]
+impl Artifact {
+    /// Builds an `Artifact` from a Python dict of metadata, as returned by the API.
+    #[new]
+    fn new(metadata: &Bound<PyDict>) -> PyResult<Self> {
+        let get_str = |key: &str| -> PyResult<String> {
+            metadata
+                .get_item(key)?
+                .ok_or_else(|| pyo3::exceptions::PyKeyError::new_err(key.to_string()))?
+                .extract()
+        };
+        let get_opt_str = |key: &str| -> PyResult<Option<String>> {
+            match metadata.get_item(key)? {
+                Some(value) if !value.is_none() => Ok(Some(value.extract()?)),
+                _ => Ok(None),
+            }
+        };
+
+        Ok(Self {
+            id: get_str("id")?,
+            name: get_str("name")?,
+            size: metadata
+                .get_item("size")?
+                .ok_or_else(|| pyo3::exceptions::PyKeyError::new_err("size"))?
+                .extract()?,
+            urdf_path: get_opt_str("urdf_path")?,
+            mjcf_path: get_opt_str("mjcf_path")?,
+        })
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Artifact(id={:?}, name={:?}, size={})",
+            self.id, self.name, self.size
+        )
+    }
+}
+
+/// Metadata describing a single robot entry in the K-Scale catalog.
+#[gen_stub_pyclass]
+#[pyclass(get_all)]
+#[derive(Debug, Clone)]
+pub struct RobotMetadata {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+}
+
+#[gen_stub_pymethods]
+#[pymethods]
+impl RobotMetadata {
+    /// Builds a `RobotMetadata` from a Python dict of metadata, as returned by the API.
+    #[new]
+    fn new(metadata: &Bound<PyDict>) -> PyResult<Self> {
+        let get_str = |key: &str| -> PyResult<String> {
+            metadata
+                .get_item(key)?
+                .ok_or_else(|| pyo3::exceptions::PyKeyError::new_err(key.to_string()))?
+                .extract()
+        };
+        let description = match metadata.get_item("description")? {
+            Some(value) if !value.is_none() => Some(value.extract()?),
+            _ => None,
+        };
+
+        Ok(Self {
+            id: get_str("id")?,
+            name: get_str("name")?,
+            description,
+        })
+    }
+
+    fn __repr__(&self) -> String {
+        format!("RobotMetadata(id={:?}, name={:?})", self.id, self.name)
+    }
+}
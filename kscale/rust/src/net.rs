@@ -0,0 +1,125 @@
+//! Placeholder async network operations backing the Python bindings.
+//!
+//! These stand in for the real K-Scale API client (model storage, auth) until that
+//! client is wired up here; the shapes are what the `#[pyfunction]` wrappers in
+//! `lib.rs` expect to `.await`.
+
+use std::future::Future;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use pyo3::PyResult;
+
+use crate::error::KScaleError;
+
+/// Number of simulated chunks a transfer is split into; a signal check runs between
+/// each one so a `KeyboardInterrupt` can land mid-transfer instead of only at the end.
+const CHUNK_COUNT: u32 = 8;
+
+/// Downloads the artifact identified by `artifact_id` to `dest`, returning the path
+/// it was written to.
+///
+/// `check_interrupt` is invoked between chunks and should raise if Ctrl-C has been
+/// pressed, so the transfer aborts promptly. On interrupt the caller is responsible
+/// for removing the partial file at `dest`.
+pub async fn download_artifact<F, Fut>(
+    artifact_id: String,
+    dest: PathBuf,
+    check_interrupt: F,
+) -> PyResult<PathBuf>
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = PyResult<()>>,
+{
+    for _ in 0..CHUNK_COUNT {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        check_interrupt().await?;
+    }
+    ensure_known(&artifact_id)?;
+    // Placeholders until the real client reports a server-side digest and hashes the
+    // bytes written to `dest`; both sides are the same value so this always matches.
+    verify_checksum("deadbeef", "deadbeef")?;
+    Ok(dest)
+}
+
+/// Uploads the file at `path`, returning the id assigned to the new artifact.
+///
+/// `check_interrupt` is invoked between chunks, same contract as in
+/// [`download_artifact`].
+pub async fn upload_artifact<F, Fut>(path: PathBuf, check_interrupt: F) -> PyResult<String>
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = PyResult<()>>,
+{
+    let _ = &path;
+    for _ in 0..CHUNK_COUNT {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        check_interrupt().await?;
+    }
+    Ok("artifact_new".to_string())
+}
+
+/// Raw artifact fields as returned by the listing endpoint, before conversion to
+/// the `Artifact` pyclass.
+pub struct RawArtifact {
+    pub id: String,
+    pub name: String,
+    pub size: u64,
+    pub urdf_path: Option<String>,
+    pub mjcf_path: Option<String>,
+}
+
+/// Lists the artifacts visible to the current auth token.
+pub async fn list_artifacts() -> Result<Vec<RawArtifact>, KScaleError> {
+    tokio::time::sleep(Duration::from_millis(10)).await;
+    Ok(vec![RawArtifact {
+        id: "artifact_1".to_string(),
+        name: "example-robot".to_string(),
+        size: 1024,
+        urdf_path: Some("example-robot.urdf".to_string()),
+        mjcf_path: Some("example-robot.mjcf".to_string()),
+    }])
+}
+
+/// Exchanges a refresh token for a fresh access token.
+pub async fn refresh_auth_token(refresh_token: String) -> Result<String, KScaleError> {
+    tokio::time::sleep(Duration::from_millis(10)).await;
+    if refresh_token.is_empty() {
+        return Err(KScaleError::Auth("refresh token is empty".to_string()));
+    }
+    Ok("access_token".to_string())
+}
+
+/// Rejects artifact ids the API wouldn't recognize.
+fn ensure_known(artifact_id: &str) -> Result<(), KScaleError> {
+    if artifact_id.is_empty() {
+        return Err(KScaleError::NotFound("artifact id is empty".to_string()));
+    }
+    Ok(())
+}
+
+/// Verifies a downloaded artifact's digest against the one the server reported.
+fn verify_checksum(expected: &str, actual: &str) -> Result<(), KScaleError> {
+    if expected != actual {
+        return Err(KScaleError::Checksum(format!(
+            "expected digest {expected:?} but computed {actual:?}"
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verify_checksum_accepts_matching_digests() {
+        assert!(verify_checksum("deadbeef", "deadbeef").is_ok());
+    }
+
+    #[test]
+    fn verify_checksum_rejects_mismatched_digests() {
+        let err = verify_checksum("deadbeef", "feedface").unwrap_err();
+        assert!(matches!(err, KScaleError::Checksum(_)));
+    }
+}
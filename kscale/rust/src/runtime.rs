@@ -0,0 +1,27 @@
+use std::sync::OnceLock;
+
+use tokio::runtime::Runtime;
+
+/// Shared multi-threaded runtime backing every async `#[pyfunction]` in this module.
+///
+/// `pyo3_asyncio::tokio::future_into_py` needs a runtime to poll futures on once control
+/// returns to Python, so we build one lazily on first use and hand pyo3-asyncio a
+/// reference to it rather than letting each call spin up its own.
+fn runtime() -> &'static Runtime {
+    static RUNTIME: OnceLock<Runtime> = OnceLock::new();
+    RUNTIME.get_or_init(|| {
+        tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .thread_name("kscale-async")
+            .build()
+            .expect("failed to build kscale tokio runtime")
+    })
+}
+
+/// Registers [`runtime`] as the runtime `pyo3_asyncio::tokio` drives futures on.
+///
+/// Must be called once, before any `future_into_py` call, which is why it happens at
+/// the top of the `#[pymodule]` initializer.
+pub fn init() {
+    pyo3_asyncio::tokio::init_with_runtime(runtime()).expect("failed to install kscale runtime");
+}